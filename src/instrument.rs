@@ -0,0 +1,209 @@
+use crate::Note;
+
+/// A stringed instrument, modelled as a list of open-string `Note`s (from
+/// lowest to highest) and a number of available frets.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Instrument;
+///
+/// let guitar = Instrument::standard_guitar();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Instrument {
+    open_strings: Vec<Note>,
+    frets: u8,
+}
+
+impl Instrument {
+    /// Creates a new `Instrument` with the given `open_strings` (lowest to
+    /// highest) and number of `frets`.
+    pub fn new(open_strings: Vec<Note>, frets: u8) -> Self {
+        Self {
+            open_strings,
+            frets,
+        }
+    }
+
+    /// Creates a standard 6-string guitar, tuned E2-A2-D3-G3-B3-E4, with 24
+    /// frets.
+    pub fn standard_guitar() -> Self {
+        Self::new(
+            vec![28, 33, 38, 43, 47, 52]
+                .into_iter()
+                .map(Note::new)
+                .collect(),
+            24,
+        )
+    }
+
+    /// Creates a standard 4-string bass, tuned E1-A1-D2-G2, with 24 frets.
+    pub fn standard_bass() -> Self {
+        Self::new(
+            vec![16, 21, 26, 31].into_iter().map(Note::new).collect(),
+            24,
+        )
+    }
+
+    /// Arranges a sequence of `notes` into playable fretboard positions,
+    /// returned as `(string, fret)` pairs (string 0 is the lowest string).
+    ///
+    /// Positions are chosen with a Viterbi-style dynamic program that
+    /// minimises the cumulative biomechanical cost of moving between
+    /// consecutive positions, so the resulting fingering is comfortable to
+    /// play rather than just the first match found.
+    ///
+    /// Returns an error if any note can't be played on this instrument,
+    /// i.e. it doesn't fall within `frets` of any open string.
+    pub fn arrange(&self, notes: &[Note]) -> Result<Vec<(u8, u8)>, anyhow::Error> {
+        if notes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let candidates = notes
+            .iter()
+            .map(|note| self.positions_for(note))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // dp[i][j] holds the cheapest cumulative cost of reaching
+        // candidates[i][j], along with the index into candidates[i - 1]
+        // that achieved it
+        let mut dp: Vec<Vec<(f64, Option<usize>)>> = Vec::with_capacity(candidates.len());
+
+        for (i, positions) in candidates.iter().enumerate() {
+            let row = positions
+                .iter()
+                .map(|&position| {
+                    if i == 0 {
+                        (0.0, None)
+                    } else {
+                        dp[i - 1]
+                            .iter()
+                            .zip(&candidates[i - 1])
+                            .enumerate()
+                            .map(|(j, (&(prev_cost, _), &prev_position))| {
+                                (prev_cost + Self::transition_cost(prev_position, position), j)
+                            })
+                            .fold((f64::INFINITY, None), |best, (cost, j)| {
+                                if cost < best.0 {
+                                    (cost, Some(j))
+                                } else {
+                                    best
+                                }
+                            })
+                    }
+                })
+                .collect();
+
+            dp.push(row);
+        }
+
+        // Backtrack from the cheapest final position to recover the path
+        let mut index = dp
+            .last()
+            .expect("notes is non-empty")
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.0.partial_cmp(&b.0).unwrap())
+            .map(|(j, _)| j)
+            .expect("every note has at least one candidate position");
+
+        let mut path = Vec::with_capacity(candidates.len());
+        for (i, positions) in candidates.iter().enumerate().rev() {
+            path.push(positions[index]);
+            if let Some(prev) = dp[i][index].1 {
+                index = prev;
+            }
+        }
+        path.reverse();
+
+        Ok(path)
+    }
+
+    /// Returns every `(string, fret)` position at which `note` can be
+    /// played on this instrument.
+    fn positions_for(&self, note: &Note) -> Result<Vec<(u8, u8)>, anyhow::Error> {
+        let positions: Vec<(u8, u8)> = self
+            .open_strings
+            .iter()
+            .enumerate()
+            .filter_map(|(string, open_string)| {
+                let fret = note.value.checked_sub(open_string.value)?;
+                (fret <= self.frets as usize).then_some((string as u8, fret as u8))
+            })
+            .collect();
+
+        if positions.is_empty() {
+            Err(anyhow::anyhow!(
+                "{} is not playable on this instrument",
+                note
+            ))
+        } else {
+            Ok(positions)
+        }
+    }
+
+    /// The biomechanical cost of moving from fretboard position `a` to
+    /// position `b`, penalising large jumps in fret or string and
+    /// discouraging open strings (which break the hand's fixed position).
+    fn transition_cost(a: (u8, u8), b: (u8, u8)) -> f64 {
+        let (string_a, fret_a) = (a.0 as f64, a.1 as f64);
+        let (string_b, fret_b) = (b.0 as f64, b.1 as f64);
+
+        let mut cost = (fret_a - fret_b).abs()
+            + 0.3 * (string_a - string_b).abs()
+            + 0.3 * (fret_a + fret_b)
+            + 0.5 * (string_a + string_b);
+
+        if a.1 == 0 || b.1 == 0 {
+            cost += 8.0;
+        }
+
+        cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_playable_position_per_note() {
+        let guitar = Instrument::standard_guitar();
+        let notes = vec![Note::new(28), Note::new(33), Note::new(40)];
+
+        let positions = guitar.arrange(&notes).unwrap();
+
+        assert_eq!(positions.len(), notes.len());
+        for (position, note) in positions.iter().zip(&notes) {
+            let open_string = guitar.open_strings[position.0 as usize];
+            assert_eq!(open_string.value + position.1 as usize, note.value);
+        }
+    }
+
+    #[test]
+    fn avoids_open_strings_when_an_alternative_exists() {
+        let guitar = Instrument::standard_guitar();
+        // D3 (38), E3 (40), F#3 (42) are playable on the D string at frets
+        // 0, 2 and 4, but each also has a fretted alternative elsewhere
+        // on the neck, which the open-string penalty should favour
+        let notes = vec![Note::new(38), Note::new(40), Note::new(42)];
+
+        let positions = guitar.arrange(&notes).unwrap();
+
+        assert!(positions.iter().all(|&(_, fret)| fret != 0));
+    }
+
+    #[test]
+    fn unplayable_note_is_an_error() {
+        let guitar = Instrument::standard_guitar();
+        assert!(guitar.arrange(&[Note::new(0)]).is_err());
+    }
+
+    #[test]
+    fn empty_sequence_arranges_to_nothing() {
+        let guitar = Instrument::standard_guitar();
+        assert_eq!(guitar.arrange(&[]).unwrap(), Vec::new());
+    }
+}
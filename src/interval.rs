@@ -0,0 +1,128 @@
+use std::{fmt, str::FromStr};
+
+/// The distance between two `Note`s, measured in semitones.
+///
+/// Unlike a bare semitone count, an `Interval` carries musical meaning: it
+/// can be constructed from (and displayed as) standard interval names such
+/// as "m3" (minor third) or "P5" (perfect fifth).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::Interval;
+///
+/// assert_eq!(Interval::PER5.semitones, 7);
+/// assert_eq!(Interval::PER5.to_string(), "P5");
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Interval {
+    pub semitones: usize,
+}
+
+impl Interval {
+    pub const UNISON: Self = Self { semitones: 0 };
+    pub const MIN2: Self = Self { semitones: 1 };
+    pub const MAJ2: Self = Self { semitones: 2 };
+    pub const MIN3: Self = Self { semitones: 3 };
+    pub const MAJ3: Self = Self { semitones: 4 };
+    pub const PER4: Self = Self { semitones: 5 };
+    pub const TRIT: Self = Self { semitones: 6 };
+    pub const PER5: Self = Self { semitones: 7 };
+    pub const MIN6: Self = Self { semitones: 8 };
+    pub const MAJ6: Self = Self { semitones: 9 };
+    pub const MIN7: Self = Self { semitones: 10 };
+    pub const MAJ7: Self = Self { semitones: 11 };
+    pub const OCTAVE: Self = Self { semitones: 12 };
+
+    /// Creates a new `Interval` spanning the given number of `semitones`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Interval;
+    ///
+    /// let fifth = Interval::new(7);
+    /// assert_eq!(fifth, Interval::PER5);
+    /// ```
+    pub fn new(semitones: usize) -> Self {
+        Self { semitones }
+    }
+
+    /// Returns the name of this `Interval`, if it has one.
+    ///
+    /// Only intervals of an octave or less (0 to 12 semitones) have a
+    /// standard name.
+    fn name(&self) -> Option<&'static str> {
+        match self.semitones {
+            0 => Some("P1"),
+            1 => Some("m2"),
+            2 => Some("M2"),
+            3 => Some("m3"),
+            4 => Some("M3"),
+            5 => Some("P4"),
+            6 => Some("A4"),
+            7 => Some("P5"),
+            8 => Some("m6"),
+            9 => Some("M6"),
+            10 => Some("m7"),
+            11 => Some("M7"),
+            12 => Some("P8"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{} semitones", self.semitones),
+        }
+    }
+}
+
+impl FromStr for Interval {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let semitones = match s {
+            "P1" => 0,
+            "m2" => 1,
+            "M2" => 2,
+            "m3" => 3,
+            "M3" => 4,
+            "P4" => 5,
+            "A4" | "d5" => 6,
+            "P5" => 7,
+            "m6" => 8,
+            "M6" => 9,
+            "m7" => 10,
+            "M7" => 11,
+            "P8" => 12,
+            _ => return Err(anyhow::anyhow!("failed to parse interval name")),
+        };
+
+        Ok(Self::new(semitones))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Interval::MIN3.to_string(), "m3");
+        assert_eq!(Interval::PER5.to_string(), "P5");
+        assert_eq!(Interval::new(19).to_string(), "19 semitones");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Interval::from_str("m3").unwrap(), Interval::MIN3);
+        assert_eq!(Interval::from_str("P5").unwrap(), Interval::PER5);
+        assert_eq!(Interval::from_str("d5").unwrap(), Interval::TRIT);
+
+        assert!(Interval::from_str("Z9").is_err());
+    }
+}
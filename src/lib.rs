@@ -0,0 +1,11 @@
+mod instrument;
+mod interval;
+mod melody;
+mod note;
+mod scale;
+
+pub use instrument::Instrument;
+pub use interval::Interval;
+pub use melody::Melody;
+pub use note::{Note, NoteIter, SpelledNote, Spelling};
+pub use scale::{Scale, ScaleIter};
@@ -0,0 +1,169 @@
+use crate::{Note, Scale};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A procedural melody generator, producing a sequence of `Note`s
+/// constrained to a given `Scale` and register.
+///
+/// `Melody` implements `Iterator`, so the generated notes can be fed
+/// straight into downstream code (e.g. for playback via
+/// [`Note::frequency`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Melody, Note, Scale};
+///
+/// let melody = Melody::with_seed(
+///     Scale::major(Note::new(0)),
+///     Note::new(48),
+///     Note::new(72),
+///     8,
+///     42,
+/// );
+/// let notes: Vec<Note> = melody.collect();
+///
+/// assert_eq!(notes.len(), 8);
+/// assert!(notes
+///     .iter()
+///     .all(|note| note.value >= 48 && note.value <= 72));
+/// ```
+pub struct Melody {
+    degrees: Vec<Note>,
+    length: usize,
+    emitted: usize,
+    stepwise_bias: f64,
+    current: Option<usize>,
+    rng: StdRng,
+}
+
+impl Melody {
+    /// Creates a new `Melody`, sampling `length` notes from `scale` that
+    /// fall between `low` and `high` (inclusive).
+    ///
+    /// Uses an OS-seeded RNG, so the output differs between runs. See
+    /// [`Melody::with_seed`] for reproducible output.
+    pub fn new(scale: Scale, low: Note, high: Note, length: usize) -> Self {
+        Self::new_with_rng(scale, low, high, length, StdRng::from_entropy())
+    }
+
+    /// Creates a new `Melody` as in [`Melody::new`], but seeded with `seed`
+    /// so the same arguments always produce the same sequence of notes.
+    pub fn with_seed(scale: Scale, low: Note, high: Note, length: usize, seed: u64) -> Self {
+        Self::new_with_rng(scale, low, high, length, StdRng::seed_from_u64(seed))
+    }
+
+    fn new_with_rng(scale: Scale, low: Note, high: Note, length: usize, rng: StdRng) -> Self {
+        let degrees = scale
+            .into_iter()
+            .take_while(|note| note.value <= high.value)
+            .filter(|note| note.value >= low.value)
+            .collect();
+
+        Self {
+            degrees,
+            length,
+            emitted: 0,
+            stepwise_bias: 0.7,
+            current: None,
+            rng,
+        }
+    }
+
+    /// Sets the probability (0.0-1.0) that each subsequent note moves to an
+    /// adjacent scale degree rather than leaping elsewhere in the range.
+    /// Defaults to `0.7`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::{Melody, Note, Scale};
+    ///
+    /// let melody = Melody::with_seed(
+    ///     Scale::major(Note::new(0)),
+    ///     Note::new(48),
+    ///     Note::new(72),
+    ///     8,
+    ///     42,
+    /// )
+    /// .with_stepwise_bias(0.9);
+    /// ```
+    pub fn with_stepwise_bias(mut self, bias: f64) -> Self {
+        self.stepwise_bias = bias.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Iterator for Melody {
+    type Item = Note;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.emitted >= self.length || self.degrees.is_empty() {
+            return None;
+        }
+
+        let index = match self.current {
+            // The first note is picked from anywhere in the range
+            None => self.rng.gen_range(0..self.degrees.len()),
+            Some(current) => {
+                if self.rng.gen_bool(self.stepwise_bias) {
+                    // At either end of the range, step back inwards
+                    let last = self.degrees.len() - 1;
+                    let step: isize = if current == 0 {
+                        1
+                    } else if current == last {
+                        -1
+                    } else if self.rng.gen_bool(0.5) {
+                        1
+                    } else {
+                        -1
+                    };
+                    (current as isize + step).clamp(0, last as isize) as usize
+                } else {
+                    self.rng.gen_range(0..self.degrees.len())
+                }
+            }
+        };
+
+        self.current = Some(index);
+        self.emitted += 1;
+
+        Some(self.degrees[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range() -> (Note, Note) {
+        (Note::new(48), Note::new(72))
+    }
+
+    #[test]
+    fn produces_the_requested_length() {
+        let (low, high) = range();
+        let melody = Melody::with_seed(Scale::major(Note::new(0)), low, high, 16, 1);
+        assert_eq!(melody.count(), 16);
+    }
+
+    #[test]
+    fn stays_within_range() {
+        let (low, high) = range();
+        let melody = Melody::with_seed(Scale::major(Note::new(0)), low, high, 100, 2);
+
+        for note in melody {
+            assert!(note.value >= low.value && note.value <= high.value);
+        }
+    }
+
+    #[test]
+    fn same_seed_is_reproducible() {
+        let (low, high) = range();
+        let a: Vec<Note> =
+            Melody::with_seed(Scale::major(Note::new(0)), low, high, 16, 7).collect();
+        let b: Vec<Note> =
+            Melody::with_seed(Scale::major(Note::new(0)), low, high, 16, 7).collect();
+
+        assert_eq!(a, b);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{Interval, Scale};
 use nom::{branch::alt, bytes::complete::tag, combinator::map};
 use std::{
     cmp::Ordering,
@@ -34,18 +35,18 @@ use std::{
 /// assert_eq!(C1 - 2, Note::new(10));
 /// ```
 ///
-/// You can also get the semitone difference between two `Note`s just by
-/// subtracting them:
+/// You can also get the interval between two `Note`s just by subtracting
+/// them, as long as it's no more than an octave:
 ///
 /// ```rust
-/// use minstrel::Note;
+/// use minstrel::{Interval, Note};
 ///
 /// let C0 = Note::new(0);
 /// let E0 = Note::new(4);
 ///
 /// // It doesn't matter which order the notes are in
-/// assert_eq!(C0 - E0, 4);
-/// assert_eq!(E0 - C0, 4);
+/// assert_eq!(C0 - E0, Some(Interval::MAJ3));
+/// assert_eq!(E0 - C0, Some(Interval::MAJ3));
 /// ```
 ///
 /// Finally, you can call `into_iter` on a `Note` to iterate over it:
@@ -97,6 +98,198 @@ impl Note {
             value: self.value % 12,
         }
     }
+
+    /// Returns the MIDI note number corresponding to this `Note`.
+    ///
+    /// Since a `value` of 0 represents C0, and MIDI note 0 is C-1, the
+    /// conversion is a simple offset of 12.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    ///
+    /// let A4 = Note::new(57);
+    /// assert_eq!(A4.to_midi(), 69);
+    /// ```
+    pub fn to_midi(&self) -> i32 {
+        self.value as i32 + 12
+    }
+
+    /// Returns the frequency (in Hz) of this `Note`, assuming equal
+    /// temperament and a concert pitch of A4 = 440 Hz.
+    ///
+    /// See [`Note::frequency_with_pitch`] to use a different concert pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    ///
+    /// let A4 = Note::new(57);
+    /// assert_eq!(A4.frequency(), 440.0);
+    /// ```
+    pub fn frequency(&self) -> f64 {
+        self.frequency_with_pitch(440.0)
+    }
+
+    /// Returns the frequency (in Hz) of this `Note`, assuming equal
+    /// temperament and the given concert pitch for A4.
+    ///
+    /// This is useful for microtonal or alternate-tuning purposes, where a
+    /// reference pitch other than 440 Hz is desired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    ///
+    /// let A4 = Note::new(57);
+    /// assert_eq!(A4.frequency_with_pitch(432.0), 432.0);
+    /// ```
+    pub fn frequency_with_pitch(&self, concert_pitch: f64) -> f64 {
+        concert_pitch * 2f64.powf((self.to_midi() - 69) as f64 / 12.0)
+    }
+
+    /// Creates a new `Note` from a frequency (in Hz), assuming equal
+    /// temperament and a concert pitch of A4 = 440 Hz.
+    ///
+    /// The resulting `Note` is rounded to the nearest semitone.
+    ///
+    /// See [`Note::from_frequency_with_pitch`] to use a different concert
+    /// pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    ///
+    /// assert_eq!(Note::from_frequency(440.0), Note::new(57));
+    /// ```
+    pub fn from_frequency(frequency: f64) -> Self {
+        Self::from_frequency_with_pitch(frequency, 440.0)
+    }
+
+    /// Creates a new `Note` from a frequency (in Hz), assuming equal
+    /// temperament and the given concert pitch for A4.
+    ///
+    /// The resulting `Note` is rounded to the nearest semitone, and clamped
+    /// at `Note::new(0)` (C0) for frequencies below it, since `value` can't
+    /// represent a note lower than that.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    ///
+    /// assert_eq!(Note::from_frequency_with_pitch(432.0, 432.0), Note::new(57));
+    /// assert_eq!(Note::from_frequency_with_pitch(1.0, 440.0), Note::new(0));
+    /// ```
+    pub fn from_frequency_with_pitch(frequency: f64, concert_pitch: f64) -> Self {
+        let midi = (69.0 + 12.0 * (frequency / concert_pitch).log2()).round() as i32;
+        Self::new((midi - 12).max(0) as usize)
+    }
+
+    /// Returns this `Note` paired with the [`Spelling`] appropriate for the
+    /// given `scale`, which can be displayed to render sharp or flat
+    /// accidentals as fits the key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::{Note, Scale};
+    ///
+    /// let d_major = Scale::major(Note::new(2));
+    /// assert_eq!(format!("{}", Note::new(6).spelled_in(&d_major)), "F#");
+    ///
+    /// let f_major = Scale::major(Note::new(5));
+    /// assert_eq!(format!("{}", Note::new(10).spelled_in(&f_major)), "Bb");
+    /// ```
+    pub fn spelled_in(&self, scale: &Scale) -> SpelledNote {
+        SpelledNote {
+            note: *self,
+            spelling: scale.spelling(),
+        }
+    }
+
+    /// Transposes this `Note` by a signed number of `semitones`, returning
+    /// `None` if the result would fall below `Note::new(0)`.
+    ///
+    /// Unlike the `+`/`-` operators with `usize`, this allows transposing
+    /// downwards without risking an underflow panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::Note;
+    ///
+    /// assert_eq!(Note::new(5).transpose(-3), Some(Note::new(2)));
+    /// assert_eq!(Note::new(5).transpose(-10), None);
+    /// ```
+    pub fn transpose(&self, semitones: i32) -> Option<Self> {
+        let value = self.value as i32 + semitones;
+
+        if value < 0 {
+            None
+        } else {
+            Some(Self::new(value as usize))
+        }
+    }
+}
+
+/// Whether a `Note` should be displayed using sharp or flat accidentals.
+///
+/// See [`Note::spelled_in`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spelling {
+    Flat,
+    Sharp,
+}
+
+impl Spelling {
+    fn name(&self, pitch_class: usize) -> &'static str {
+        match (self, pitch_class) {
+            (_, 0) => "C",
+            (Spelling::Sharp, 1) => "C#",
+            (Spelling::Flat, 1) => "Db",
+            (_, 2) => "D",
+            (Spelling::Sharp, 3) => "D#",
+            (Spelling::Flat, 3) => "Eb",
+            (_, 4) => "E",
+            (_, 5) => "F",
+            (Spelling::Sharp, 6) => "F#",
+            (Spelling::Flat, 6) => "Gb",
+            (_, 7) => "G",
+            (Spelling::Sharp, 8) => "G#",
+            (Spelling::Flat, 8) => "Ab",
+            (_, 9) => "A",
+            (Spelling::Sharp, 10) => "A#",
+            (Spelling::Flat, 10) => "Bb",
+            (_, 11) => "B",
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A `Note` paired with a [`Spelling`], rendering it with sharp or flat
+/// accidentals as appropriate for a particular key.
+///
+/// Created via [`Note::spelled_in`].
+pub struct SpelledNote {
+    note: Note,
+    spelling: Spelling,
+}
+
+impl fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.spelling.name(self.note.value % 12);
+
+        if f.alternate() {
+            write!(f, "{}{}", name, self.note.value / 12)
+        } else {
+            write!(f, "{}", name)
+        }
+    }
 }
 
 impl FromStr for Note {
@@ -104,17 +297,22 @@ impl FromStr for Note {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (s, name) = alt((
-            map(tag("C"), |_| 0),
             map(tag("Db"), |_| 1),
-            map(tag("D"), |_| 2),
+            map(tag("C#"), |_| 1),
+            map(tag("C"), |_| 0),
+            map(tag("D#"), |_| 3),
             map(tag("Eb"), |_| 3),
+            map(tag("D"), |_| 2),
             map(tag("E"), |_| 4),
-            map(tag("F"), |_| 5),
+            map(tag("F#"), |_| 6),
             map(tag("Gb"), |_| 6),
-            map(tag("G"), |_| 7),
+            map(tag("F"), |_| 5),
+            map(tag("G#"), |_| 8),
             map(tag("Ab"), |_| 8),
-            map(tag("A"), |_| 9),
+            map(tag("G"), |_| 7),
+            map(tag("A#"), |_| 10),
             map(tag("Bb"), |_| 10),
+            map(tag("A"), |_| 9),
             map(tag("B"), |_| 11),
         ))(s)
         .map_err(|_: nom::Err<(&str, nom::error::ErrorKind)>| {
@@ -140,6 +338,51 @@ fn parsing() {
     assert!(Note::from_str("Gb-2").is_err()); // Invalid octave number
 }
 
+#[cfg(test)]
+#[test]
+fn parsing_sharps() {
+    assert_eq!(Note::from_str("C#0").unwrap(), Note::new(1));
+    assert_eq!(Note::from_str("F#3").unwrap(), Note::new(42));
+    assert_eq!(Note::from_str("A#").unwrap(), Note::new(10));
+
+    // Sharps round-trip back to the same value as their flat equivalent
+    assert_eq!(Note::from_str("D#2").unwrap(), Note::from_str("Eb2").unwrap());
+}
+
+#[cfg(test)]
+mod spelling_tests {
+    use super::*;
+
+    #[test]
+    fn sharp_key() {
+        let d_major = Scale::major(Note::new(2));
+        assert_eq!(Note::new(6).spelled_in(&d_major).to_string(), "F#");
+        assert_eq!(Note::new(1).spelled_in(&d_major).to_string(), "C#");
+    }
+
+    #[test]
+    fn flat_key() {
+        let f_major = Scale::major(Note::new(5));
+        assert_eq!(Note::new(10).spelled_in(&f_major).to_string(), "Bb");
+        assert_eq!(Note::new(3).spelled_in(&f_major).to_string(), "Eb");
+    }
+
+    #[test]
+    fn natural_key() {
+        let c_major = Scale::major(Note::new(0));
+        let a_minor = Scale::natural_minor(Note::new(9));
+
+        assert_eq!(Note::new(1).spelled_in(&c_major).to_string(), "Db");
+        assert_eq!(Note::new(1).spelled_in(&a_minor).to_string(), "Db");
+    }
+
+    #[test]
+    fn alternate_includes_octave() {
+        let d_major = Scale::major(Note::new(2));
+        assert_eq!(format!("{:#}", Note::new(18).spelled_in(&d_major)), "F#1");
+    }
+}
+
 impl fmt::Display for Note {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = match self.value % 12 {
@@ -216,24 +459,85 @@ fn transposition() {
 }
 
 impl Sub for Note {
-    type Output = usize;
+    type Output = Option<Interval>;
 
-    // Outputs the semitone difference between the two note values
+    // Outputs the interval between the two note values, if the semitone
+    // difference is small enough to have a standard name (i.e. an octave
+    // or less)
     fn sub(self, other: Self) -> Self::Output {
-        match self.value.cmp(&other.value) {
+        let semitones = match self.value.cmp(&other.value) {
             Ordering::Greater => self.value - other.value,
             Ordering::Less => other.value - self.value,
             Ordering::Equal => 0,
+        };
+
+        if semitones <= 12 {
+            Some(Interval::new(semitones))
+        } else {
+            None
         }
     }
 }
 
+impl Add<Interval> for Note {
+    type Output = Self;
+
+    fn add(self, interval: Interval) -> Self::Output {
+        self + interval.semitones
+    }
+}
+
+impl Sub<Interval> for Note {
+    type Output = Self;
+
+    fn sub(self, interval: Interval) -> Self::Output {
+        self - interval.semitones
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn interval_transposition() {
+    assert_eq!(Note::new(10) + Interval::PER5, Note::new(17));
+    assert_eq!(Note::new(10) - Interval::MIN3, Note::new(7));
+}
+
+#[cfg(test)]
+#[test]
+fn signed_transposition() {
+    assert_eq!(Note::new(10).transpose(5), Some(Note::new(15)));
+    assert_eq!(Note::new(10).transpose(-5), Some(Note::new(5)));
+
+    assert_eq!(Note::new(2).transpose(-5), None);
+}
+
+#[cfg(test)]
+#[test]
+fn pitch_conversion() {
+    assert_eq!(Note::new(57).to_midi(), 69);
+    assert_eq!(Note::new(0).to_midi(), 12);
+
+    assert_eq!(Note::new(57).frequency(), 440.0);
+    assert_eq!(Note::new(57).frequency_with_pitch(432.0), 432.0);
+
+    assert_eq!(Note::from_frequency(440.0), Note::new(57));
+    assert_eq!(Note::from_frequency_with_pitch(432.0, 432.0), Note::new(57));
+}
+
+#[cfg(test)]
+#[test]
+fn from_frequency_clamps_below_c0() {
+    assert_eq!(Note::from_frequency(10.0), Note::new(0));
+    assert_eq!(Note::from_frequency_with_pitch(1.0, 440.0), Note::new(0));
+}
+
 #[cfg(test)]
 #[test]
 fn interval_calculation() {
-    assert_eq!(Note::new(10) - Note::new(5), 5);
-    assert_eq!(Note::new(21) - Note::new(27), 6);
-    assert_eq!(Note::new(37) - Note::new(37), 0);
+    assert_eq!(Note::new(10) - Note::new(5), Some(Interval::PER4));
+    assert_eq!(Note::new(21) - Note::new(27), Some(Interval::TRIT));
+    assert_eq!(Note::new(37) - Note::new(37), Some(Interval::UNISON));
+    assert_eq!(Note::new(30) - Note::new(10), None);
 }
 
 impl IntoIterator for Note {
@@ -0,0 +1,188 @@
+use crate::{note::Spelling, Note};
+
+/// The steps of the natural minor scale, used to recognise it when picking
+/// a spelling (see [`Scale::spelling`]).
+const NATURAL_MINOR_STEPS: [usize; 7] = [2, 1, 2, 2, 1, 2, 2];
+
+/// A scale, generated from a tonic `Note` and a pattern of steps.
+///
+/// The pattern is a string made up of `'m'` (a minor/half step, 1
+/// semitone), `'M'` (a major/whole step, 2 semitones) and `'A'` (an
+/// augmented step, 3 semitones). For example, the major scale pattern is
+/// `"MMmMMMm"`.
+///
+/// `Scale` is iterable, and wraps around the octave indefinitely, so you
+/// can `.take(n)` across as many octaves as you like.
+///
+/// # Examples
+///
+/// ```rust
+/// use minstrel::{Note, Scale};
+///
+/// let c_major = Scale::major(Note::new(0));
+/// let notes: Vec<Note> = c_major.into_iter().take(8).collect();
+///
+/// assert_eq!(notes[0], Note::new(0)); // C
+/// assert_eq!(notes[7], Note::new(12)); // C, one octave up
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scale {
+    tonic: Note,
+    pattern: Vec<usize>,
+}
+
+impl Scale {
+    /// Creates a new `Scale` from a `tonic` and a `pattern` of steps, where
+    /// `'m'` = 1 semitone, `'M'` = 2 semitones and `'A'` = 3 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use minstrel::{Note, Scale};
+    ///
+    /// let c_major = Scale::new(Note::new(0), "MMmMMMm").unwrap();
+    /// ```
+    pub fn new(tonic: Note, pattern: &str) -> Result<Self, anyhow::Error> {
+        let pattern = pattern
+            .chars()
+            .map(|step| match step {
+                'm' => Ok(1),
+                'M' => Ok(2),
+                'A' => Ok(3),
+                _ => Err(anyhow::anyhow!("invalid step '{}' in scale pattern", step)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { tonic, pattern })
+    }
+
+    /// Creates the major scale starting at the given `tonic`.
+    pub fn major(tonic: Note) -> Self {
+        Self::new(tonic, "MMmMMMm").expect("major pattern is valid")
+    }
+
+    /// Creates the natural minor scale starting at the given `tonic`.
+    pub fn natural_minor(tonic: Note) -> Self {
+        Self::new(tonic, "MmMMmMM").expect("natural minor pattern is valid")
+    }
+
+    /// Creates the chromatic scale starting at the given `tonic`.
+    pub fn chromatic(tonic: Note) -> Self {
+        Self::new(tonic, "mmmmmmmmmmmm").expect("chromatic pattern is valid")
+    }
+
+    /// Returns the [`Spelling`] that matches this scale's key, used by
+    /// [`Note::spelled_in`].
+    ///
+    /// Sharp keys (G, D, A, E, B, F#) and their relative minors spell with
+    /// sharps; flat keys (F, Bb, Eb, Ab, Db) and their relative minors
+    /// spell with flats. A tonic of F#/Gb is the same `Note`, so it is
+    /// always treated as F# and spells with sharps. Everything else
+    /// (including C/Am) defaults to flats, matching `Note`'s own `Display`
+    /// impl.
+    ///
+    /// This is an intentional simplification: `Spelling` only distinguishes
+    /// sharp from flat, so C/Am (whose seven diatonic notes have no
+    /// accidentals either way) fall back to the same flat-leaning default
+    /// as `Display`, rather than a separate "natural" mode.
+    pub(crate) fn spelling(&self) -> Spelling {
+        // The natural minor shares its key signature with the major scale
+        // a minor third above its tonic, so look up the spelling there
+        let key_pitch_class = if self.pattern.as_slice() == NATURAL_MINOR_STEPS {
+            (self.tonic.value % 12 + 3) % 12
+        } else {
+            self.tonic.value % 12
+        };
+
+        match key_pitch_class {
+            7 | 2 | 9 | 4 | 11 | 6 => Spelling::Sharp,
+            _ => Spelling::Flat,
+        }
+    }
+}
+
+impl IntoIterator for Scale {
+    type Item = Note;
+    type IntoIter = ScaleIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ScaleIter {
+            note: self.tonic,
+            pattern: self.pattern,
+            step: 0,
+            first: true,
+        }
+    }
+}
+
+/// An iterator over a `Scale`, yielding its `Note`s in ascending order and
+/// wrapping around the octave indefinitely.
+pub struct ScaleIter {
+    note: Note,
+    pattern: Vec<usize>,
+    step: usize,
+    first: bool,
+}
+
+impl Iterator for ScaleIter {
+    type Item = Note;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Returns the tonic if this was the first iteration
+        if self.first {
+            self.first = false;
+            return Some(self.note);
+        }
+
+        let semitones = self.pattern[self.step % self.pattern.len()];
+        self.note = self.note + semitones;
+        self.step += 1;
+
+        Some(self.note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn major() {
+        let notes: Vec<Note> = Scale::major(Note::new(0)).into_iter().take(8).collect();
+
+        assert_eq!(
+            notes,
+            vec![0, 2, 4, 5, 7, 9, 11, 12]
+                .into_iter()
+                .map(Note::new)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn natural_minor() {
+        let notes: Vec<Note> = Scale::natural_minor(Note::new(0))
+            .into_iter()
+            .take(8)
+            .collect();
+
+        assert_eq!(
+            notes,
+            vec![0, 2, 3, 5, 7, 8, 10, 12]
+                .into_iter()
+                .map(Note::new)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn wraps_across_octaves() {
+        let notes: Vec<Note> = Scale::major(Note::new(0)).into_iter().take(9).collect();
+        assert_eq!(notes[8], Note::new(14));
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(Scale::new(Note::new(0), "MMx").is_err());
+    }
+}